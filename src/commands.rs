@@ -0,0 +1,8 @@
+//! DS18B20 function command bytes, as issued following a ROM command.
+
+pub const CONVERT_TEMP: u8 = 0x44;
+pub const WRITE_SCRATCHPAD: u8 = 0x4E;
+pub const READ_SCRATCHPAD: u8 = 0xBE;
+pub const COPY_SCRATCHPAD: u8 = 0x48;
+pub const RECALL_EEPROM: u8 = 0xB8;
+pub const READ_POWER_SUPPLY: u8 = 0xB4;
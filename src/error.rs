@@ -6,6 +6,10 @@ pub enum Error {
     FamilyCodeMismatch,
     CrcMismatch,
     Timeout,
+    /// A parasite-powered conversion was requested, but the `OneWire`
+    /// trait exposes no way to hold the bus at a strong pullup, so the
+    /// conversion can't be driven safely.
+    ParasitePowerUnsupported,
     Other,
 }
 
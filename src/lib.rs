@@ -4,13 +4,25 @@ use embedded_hal::delay::DelayNs;
 use one_wire_hal::address::Address;
 use one_wire_hal::OneWire;
 
+pub use power::PowerMode;
 pub use resolution::Resolution;
 
 use crate::error::Error;
 
+/// Family code of the DS18B20.
 pub const FAMILY_CODE: u8 = 0x28;
 
+/// Family code of the DS18S20, which shares the DS18B20 protocol but reports
+/// temperature with the extended-resolution algorithm instead of a
+/// resolution-selectable divisor.
+pub const FAMILY_CODE_DS18S20: u8 = 0x10;
+
+/// Family code of the DS1822, protocol- and encoding-compatible with the
+/// DS18B20.
+pub const FAMILY_CODE_DS1822: u8 = 0x22;
+
 pub mod commands;
+mod power;
 mod resolution;
 pub mod error;
 
@@ -35,16 +47,21 @@ pub struct SensorData {
 pub struct Ds18b20<O> {
     one_wire: O,
     address: Address,
+    family_code: u8,
 }
 
 impl<O: OneWire> Ds18b20<O> {
-    /// Checks that the given address contains the correct family code, reads
-    /// configuration data, then returns a device
+    /// Checks that the given address contains a supported family code (DS18B20,
+    /// DS18S20 or DS1822), then returns a device. No data is read from the bus.
     pub fn new(one_wire: O, address: Address) -> Result<Ds18b20<O>, Error> {
-        if address.family_code() == FAMILY_CODE {
-            Ok(Ds18b20 { one_wire, address })
-        } else {
-            Err(Error::FamilyCodeMismatch)
+        let family_code = address.family_code();
+        match family_code {
+            FAMILY_CODE | FAMILY_CODE_DS18S20 | FAMILY_CODE_DS1822 => Ok(Ds18b20 {
+                one_wire,
+                address,
+                family_code,
+            }),
+            _ => Err(Error::FamilyCodeMismatch),
         }
     }
 
@@ -71,10 +88,113 @@ impl<O: OneWire> Ds18b20<O> {
         delay: &mut impl DelayNs,
     ) -> Result<SensorData, Error>
     {
-        let data = read_data(&self.address, one_wire, delay)?;
+        let data = read_data(&self.address, self.family_code, one_wire, delay)?;
         Ok(data)
     }
 
+    /// Starts a conversion, blocks for the full `max_conversion_time_ms` of
+    /// the device's current resolution, then reads back the result. This
+    /// does not drive a strong pullup, so it only applies to externally
+    /// (non-parasite) powered sensors; see [`Ds18b20::measure_temperature_with_power_mode`]
+    /// for parasite-aware measurement.
+    pub fn measure_temperature(
+        &mut self,
+        one_wire: &mut impl OneWire,
+        delay: &mut impl DelayNs,
+    ) -> Result<SensorData, Error>
+    {
+        let resolution = self.current_resolution(one_wire, delay)?;
+        one_wire.send_command(commands::CONVERT_TEMP, Some(&self.address), delay)?;
+        delay.delay_ms(resolution.max_conversion_time_ms() as u32);
+        read_data(&self.address, self.family_code, one_wire, delay)
+    }
+
+    /// Like [`Ds18b20::measure_temperature`], but for externally (non-parasite)
+    /// powered sensors: polls the bus after starting the conversion and
+    /// returns as soon as the device signals completion, instead of always
+    /// waiting the full conversion time.
+    pub fn measure_temperature_non_blocking(
+        &mut self,
+        one_wire: &mut impl OneWire,
+        delay: &mut impl DelayNs,
+    ) -> Result<SensorData, Error>
+    {
+        let resolution = self.current_resolution(one_wire, delay)?;
+        one_wire.send_command(commands::CONVERT_TEMP, Some(&self.address), delay)?;
+
+        let max_retries = (resolution.max_conversion_time_ms() as u32 * 1000 / READ_SLOT_DURATION_MICROS) + 1;
+        let mut ready = false;
+        for _ in 0..max_retries {
+            if one_wire.read_bit(delay)? == true {
+                ready = true;
+                break;
+            }
+        }
+        if !ready {
+            return Err(Error::Timeout);
+        }
+        read_data(&self.address, self.family_code, one_wire, delay)
+    }
+
+    /// Issues the read power supply command to find out whether this device
+    /// is parasite-powered. A returned `PowerMode::Parasite` means the bus
+    /// must be held at a strong pullup for the full conversion time rather
+    /// than released and polled.
+    pub fn read_power_supply(
+        &self,
+        one_wire: &mut impl OneWire,
+        delay: &mut impl DelayNs,
+    ) -> Result<PowerMode, Error>
+    {
+        one_wire.reset(delay)?;
+        one_wire.match_address(&self.address, delay)?;
+        one_wire.write_byte(commands::READ_POWER_SUPPLY, delay)?;
+        if one_wire.read_bit(delay)? == true {
+            Ok(PowerMode::External)
+        } else {
+            Ok(PowerMode::Parasite)
+        }
+    }
+
+    /// Starts a conversion and waits for it to finish, choosing the waiting
+    /// strategy appropriate to how the device is powered. Externally powered
+    /// devices are polled and return as soon as they're ready. Parasite-powered
+    /// devices need the bus driven to a strong pullup for the conversion
+    /// window instead of being polled or merely delayed past; the `OneWire`
+    /// trait this crate is built on exposes no primitive for that, so this
+    /// returns `Error::ParasitePowerUnsupported` rather than silently
+    /// performing an unsafe plain delay. Use [`Ds18b20::read_power_supply`]
+    /// to determine which mode to pass.
+    pub fn measure_temperature_with_power_mode(
+        &mut self,
+        mode: PowerMode,
+        one_wire: &mut impl OneWire,
+        delay: &mut impl DelayNs,
+    ) -> Result<SensorData, Error>
+    {
+        match mode {
+            PowerMode::Parasite => Err(Error::ParasitePowerUnsupported),
+            PowerMode::External => self.measure_temperature_non_blocking(one_wire, delay),
+        }
+    }
+
+    /// Reads the scratchpad and decodes only its configuration register to
+    /// find the currently selected resolution. The DS18S20
+    /// has no resolution register and always converts at its fixed ~750ms
+    /// rate, so it's reported as `Bits12` here purely for timing purposes.
+    fn current_resolution(
+        &self,
+        one_wire: &mut impl OneWire,
+        delay: &mut impl DelayNs,
+    ) -> Result<Resolution, Error>
+    {
+        if self.family_code == FAMILY_CODE_DS18S20 {
+            return Ok(Resolution::Bits12);
+        }
+        let scratchpad = read_scratchpad(&self.address, one_wire, delay)?;
+        Resolution::from_config_register(scratchpad[4]).ok_or(Error::CrcMismatch)
+    }
+
     pub fn set_config<E>(
         &mut self,
         alarm_temp_low: i8,
@@ -139,6 +259,41 @@ pub fn simultaneous_save_to_eeprom(
     save_to_eeprom(None, one_wire, delay)
 }
 
+/// Runs the ALARM SEARCH ROM command across the bus, yielding the address of
+/// each device whose last conversion breached its configured
+/// `alarm_temp_low`/`alarm_temp_high` limits. Devices within their configured
+/// range simply don't respond to this command, so the bus-level ROM search
+/// only turns up the ones currently in an alarm state.
+pub fn alarm_search<'a>(
+    one_wire: &'a mut impl OneWire,
+    delay: &'a mut impl DelayNs,
+) -> impl Iterator<Item = Result<Address, Error>> + 'a
+{
+    one_wire.search(true, delay).map(|result| result.map_err(Error::from))
+}
+
+/// Runs the ordinary ROM search across the bus, yielding the address of each
+/// device whose family code matches a supported temperature sensor (DS18B20,
+/// DS18S20 or DS1822), skipping any other devices sharing the bus. Lets
+/// callers enumerate every sensor on an unknown bus without hardcoding ROM
+/// codes; wrap each address in [`Ds18b20::new`] once the caller has the
+/// owned `one_wire` handle to give it.
+pub fn discover_sensors<'a>(
+    one_wire: &'a mut impl OneWire,
+    delay: &'a mut impl DelayNs,
+) -> impl Iterator<Item = Result<Address, Error>> + 'a
+{
+    one_wire.search(false, delay).filter_map(|result| {
+        match result {
+            Ok(address) => match address.family_code() {
+                FAMILY_CODE | FAMILY_CODE_DS18S20 | FAMILY_CODE_DS1822 => Some(Ok(address)),
+                _ => None,
+            },
+            Err(err) => Some(Err(Error::from(err))),
+        }
+    })
+}
+
 pub fn read_scratchpad(
     address: &Address,
     one_wire: &mut impl OneWire,
@@ -150,29 +305,62 @@ pub fn read_scratchpad(
     one_wire.write_byte(commands::READ_SCRATCHPAD, delay)?;
     let mut scratchpad = [0; 9];
     one_wire.read_bytes(&mut scratchpad, delay)?;
-    // check_crc8(&scratchpad)?;
+    check_crc8(&scratchpad)?;
     Ok(scratchpad)
 }
 
+/// Validates the Dallas/Maxim CRC-8 (reflected polynomial 0x8C) of scratchpad
+/// bytes 0..8 against the checksum stored in byte 8.
+fn check_crc8(scratchpad: &[u8; 9]) -> Result<(), Error> {
+    let mut crc: u8 = 0;
+    for &byte in &scratchpad[..8] {
+        let mut byte = byte;
+        for _ in 0..8 {
+            let mix = (crc ^ byte) & 0x01;
+            crc >>= 1;
+            if mix != 0 {
+                crc ^= 0x8C;
+            }
+            byte >>= 1;
+        }
+    }
+    if crc == scratchpad[8] {
+        Ok(())
+    } else {
+        Err(Error::CrcMismatch)
+    }
+}
+
 fn read_data(
     address: &Address,
+    family_code: u8,
     one_wire: &mut impl OneWire,
     delay: &mut impl DelayNs,
 ) -> Result<SensorData, Error>
 {
     let scratchpad = read_scratchpad(address, one_wire, delay)?;
 
-    let resolution = if let Some(resolution) = Resolution::from_config_register(scratchpad[4]) {
-        resolution
+    let (temperature, resolution) = if family_code == FAMILY_CODE_DS18S20 {
+        let raw_temp = i16::from_le_bytes([scratchpad[0], scratchpad[1]]);
+        let temp_read = (raw_temp >> 1) as f32;
+        let count_remain = scratchpad[6] as f32;
+        let count_per_c = scratchpad[7] as f32;
+        let temperature = temp_read - 0.25 + (count_per_c - count_remain) / count_per_c;
+        (temperature, Resolution::Bits9)
     } else {
-        return Err(Error::CrcMismatch);
-    };
-    let raw_temp = u16::from_le_bytes([scratchpad[0], scratchpad[1]]);
-    let temperature = match resolution {
-        Resolution::Bits12 => (raw_temp as f32) / 16.0,
-        Resolution::Bits11 => (raw_temp as f32) / 8.0,
-        Resolution::Bits10 => (raw_temp as f32) / 4.0,
-        Resolution::Bits9 => (raw_temp as f32) / 2.0,
+        let resolution = if let Some(resolution) = Resolution::from_config_register(scratchpad[4]) {
+            resolution
+        } else {
+            return Err(Error::CrcMismatch);
+        };
+        let raw_temp = i16::from_le_bytes([scratchpad[0], scratchpad[1]]);
+        let temperature = match resolution {
+            Resolution::Bits12 => (raw_temp as f32) / 16.0,
+            Resolution::Bits11 => (raw_temp as f32) / 8.0,
+            Resolution::Bits10 => (raw_temp as f32) / 4.0,
+            Resolution::Bits9 => (raw_temp as f32) / 2.0,
+        };
+        (temperature, resolution)
     };
     Ok(SensorData {
         temperature,
@@ -0,0 +1,13 @@
+/// How the sensor is wired for power, which determines how a conversion must
+/// be waited out.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PowerMode {
+    /// The sensor has its own Vdd supply, so the bus can be released and
+    /// polled (or simply left alone) during a conversion.
+    External,
+
+    /// The sensor draws power from the data line itself. The bus must be
+    /// held at a strong pullup for the full conversion time instead of being
+    /// read, since reading it would starve the device mid-conversion.
+    Parasite,
+}
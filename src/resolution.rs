@@ -0,0 +1,45 @@
+/// The temperature conversion resolution, as stored in the configuration
+/// register (byte 4 of the scratchpad).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    Bits9,
+    Bits10,
+    Bits11,
+    Bits12,
+}
+
+impl Resolution {
+    /// Builds the configuration register byte that selects this resolution.
+    pub fn to_config_register(&self) -> u8 {
+        match self {
+            Resolution::Bits9 => 0x1F,
+            Resolution::Bits10 => 0x3F,
+            Resolution::Bits11 => 0x5F,
+            Resolution::Bits12 => 0x7F,
+        }
+    }
+
+    /// Parses a configuration register byte (scratchpad byte 4) into a
+    /// resolution, or `None` if the byte is not exactly one of the four values
+    /// that [`Resolution::to_config_register`] produces.
+    pub fn from_config_register(register: u8) -> Option<Resolution> {
+        match register {
+            0x1F => Some(Resolution::Bits9),
+            0x3F => Some(Resolution::Bits10),
+            0x5F => Some(Resolution::Bits11),
+            0x7F => Some(Resolution::Bits12),
+            _ => None,
+        }
+    }
+
+    /// The worst-case time, in milliseconds, for a temperature conversion to
+    /// complete at this resolution.
+    pub fn max_conversion_time_ms(&self) -> u16 {
+        match self {
+            Resolution::Bits9 => 94,
+            Resolution::Bits10 => 188,
+            Resolution::Bits11 => 375,
+            Resolution::Bits12 => 750,
+        }
+    }
+}